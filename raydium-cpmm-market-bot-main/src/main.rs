@@ -12,6 +12,20 @@ use anchor_client::solana_sdk::pubkey::Pubkey;
 use anchor_client::solana_sdk::transaction::Transaction;
 use anchor_client::solana_sdk::system_instruction;
 use anchor_client::solana_sdk::signature::Keypair;
+use anchor_client::solana_sdk::hash::Hash;
+use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_client::solana_sdk::nonce::state::{State as NonceState, Versions as NonceVersions};
+use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_client::solana_sdk::signature::Signature;
+use anchor_client::solana_sdk::message::{v0, VersionedMessage};
+use anchor_client::solana_sdk::transaction::VersionedTransaction;
+use anchor_client::solana_sdk::address_lookup_table_account::AddressLookupTableAccount;
+use solana_address_lookup_table_program::state::AddressLookupTable;
+use solana_remote_wallet::{
+    locator::Locator,
+    remote_keypair::generate_remote_keypair,
+    remote_wallet::{initialize_wallet_manager, DerivationPath},
+};
 use std::str::FromStr;
 use colored::Colorize;
 use spl_token::instruction::sync_native;
@@ -20,6 +34,675 @@ use spl_associated_token_account::get_associated_token_address;
 use std::sync::Arc;
 use std::fs;
 use std::path::Path;
+use serde::Serialize;
+use anchor_client::solana_client::rpc_filter::{RpcFilterType, Memcmp, MemcmpEncodedBytes};
+use anchor_client::solana_client::rpc_config::{RpcProgramAccountsConfig, RpcAccountInfoConfig};
+use solana_account_decoder::UiAccountEncoding;
+
+/// How command results are reported: human-readable log lines (the
+/// previous behavior) or a machine-readable summary for scripting, selected
+/// via `--output <format>` or the `OUTPUT_FORMAT` env var.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Display,
+    Json,
+    JsonCompact,
+}
+
+impl OutputFormat {
+    fn from_args(args: &[String]) -> Self {
+        let from_flag = args
+            .iter()
+            .position(|a| a == "--output")
+            .and_then(|idx| args.get(idx + 1))
+            .map(|v| v.as_str());
+
+        let from_env = std::env::var("OUTPUT_FORMAT").ok();
+
+        match from_flag.or(from_env.as_deref()) {
+            Some("json") => OutputFormat::Json,
+            Some("json-compact") => OutputFormat::JsonCompact,
+            _ => OutputFormat::Display,
+        }
+    }
+
+    /// Print `result` as JSON when configured for scripting; a no-op in
+    /// `Display` mode, since the `Logger` calls already covered that.
+    fn emit<T: Serialize>(self, result: &T) {
+        match self {
+            OutputFormat::Display => {}
+            OutputFormat::Json => {
+                if let Ok(s) = serde_json::to_string_pretty(result) {
+                    println!("{}", s);
+                }
+            }
+            OutputFormat::JsonCompact => {
+                if let Ok(s) = serde_json::to_string(result) {
+                    println!("{}", s);
+                }
+            }
+        }
+    }
+}
+
+/// Outcome of a single transaction within a batch command, for the
+/// structured `--output json` summary. For distribute/collect there is one
+/// per wallet; for close there is one per batched transaction, and `wallet`
+/// holds the comma-separated token accounts that batch closed.
+#[derive(Serialize)]
+struct WalletOpResult {
+    wallet: String,
+    lamports: u64,
+    signature: Option<String>,
+    success: bool,
+    error: Option<String>,
+}
+
+/// Machine-readable summary of a batch command (distribute/collect/close),
+/// emitted by `OutputFormat::emit` instead of only the "closed N, failed M"
+/// log line.
+#[derive(Serialize)]
+struct CommandSummary {
+    command: String,
+    results: Vec<WalletOpResult>,
+    succeeded: usize,
+    failed: usize,
+}
+
+/// Read the durable nonce account configured via `NONCE_ACCOUNT`, if any.
+///
+/// When set, `prepare_blockhash` (and so wrap/unwrap, `send_batch` and
+/// `--build-only`) advances this nonce instead of fetching a fresh recent
+/// blockhash, so long batch operations survive normal blockhash expiry while
+/// RPC calls are throttled or retried. Transactions queued through
+/// `TransactionExecutor` do not use the nonce; they are signed against a
+/// fresh blockhash and re-signed if it expires.
+fn configured_nonce_account() -> Option<Pubkey> {
+    std::env::var("NONCE_ACCOUNT")
+        .ok()
+        .and_then(|v| Pubkey::from_str(&v).ok())
+}
+
+/// Fetch and deserialize the current stored blockhash from a durable nonce account.
+fn fetch_nonce_blockhash(
+    rpc_client: &anchor_client::solana_client::rpc_client::RpcClient,
+    nonce_pubkey: &Pubkey,
+) -> Result<Hash, String> {
+    let account = rpc_client
+        .get_account(nonce_pubkey)
+        .map_err(|e| format!("Failed to fetch nonce account {}: {}", nonce_pubkey, e))?;
+
+    let versions: NonceVersions = bincode::deserialize(&account.data)
+        .map_err(|e| format!("Failed to deserialize nonce account {}: {}", nonce_pubkey, e))?;
+
+    match versions.state() {
+        NonceState::Initialized(data) => Ok(data.blockhash()),
+        NonceState::Uninitialized => Err(format!("Nonce account {} is not initialized", nonce_pubkey)),
+    }
+}
+
+/// Resolve the blockhash to sign against for a new transaction.
+///
+/// If a durable nonce account is configured via `NONCE_ACCOUNT`, this prepends
+/// an `advance_nonce_account` instruction (authorized by `authority_pubkey`)
+/// to `instructions` and returns the nonce's stored blockhash. Otherwise it
+/// falls back to a recent blockhash from `poll_get_latest_blockhash`.
+fn prepare_blockhash(
+    config: &Config,
+    authority_pubkey: &Pubkey,
+    mut instructions: Vec<Instruction>,
+) -> Result<(Vec<Instruction>, Hash), String> {
+    if let Some(nonce_pubkey) = configured_nonce_account() {
+        let nonce_blockhash = fetch_nonce_blockhash(&config.app_state.rpc_client, &nonce_pubkey)?;
+        let mut with_advance = vec![system_instruction::advance_nonce_account(
+            &nonce_pubkey,
+            authority_pubkey,
+        )];
+        with_advance.append(&mut instructions);
+        Ok((with_advance, nonce_blockhash))
+    } else {
+        let recent_blockhash = poll_get_latest_blockhash(&config.app_state.rpc_client)?;
+        Ok((instructions, recent_blockhash))
+    }
+}
+
+/// Where `--build-only`/`--sign-offline`/`--broadcast` hand off the
+/// serialized transaction between runs, overridable so multiple sweeps
+/// in flight don't clobber each other.
+fn offline_tx_path() -> String {
+    std::env::var("OFFLINE_TX_PATH").unwrap_or_else(|_| "offline_tx.bin".to_string())
+}
+
+/// Build a main-wallet-authority-signed transfer to `destination` as an
+/// unsigned transaction and serialize it to `offline_tx_path()`. Nothing is
+/// signed here, but the authority's pubkey is taken from
+/// `load_authority_signer`, so the authority signer (the in-file keypair or
+/// the remote wallet) is still loaded on this machine. The blockhash comes
+/// from `--blockhash`, if the caller supplied one, or otherwise from the
+/// same durable-nonce/poll fallback `prepare_blockhash` uses elsewhere —
+/// a durable nonce is what makes it safe for the transaction to sit on
+/// disk for however long it takes to carry it to an air-gapped machine,
+/// since it doesn't expire the way a polled recent blockhash would.
+async fn build_offline_transfer(
+    config: &Config,
+    destination: Pubkey,
+    lamports: u64,
+    blockhash_arg: Option<String>,
+) -> Result<(), String> {
+    let authority_pubkey = load_authority_signer(config)?.pubkey();
+    let instructions = vec![system_instruction::transfer(&authority_pubkey, &destination, lamports)];
+
+    let (instructions, recent_blockhash) = match blockhash_arg {
+        Some(blockhash) => {
+            let hash = Hash::from_str(&blockhash).map_err(|e| format!("Invalid blockhash {}: {}", blockhash, e))?;
+            (instructions, hash)
+        }
+        None => prepare_blockhash(config, &authority_pubkey, instructions)?,
+    };
+
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&authority_pubkey));
+    transaction.message.recent_blockhash = recent_blockhash;
+
+    let bytes = bincode::serialize(&transaction)
+        .map_err(|e| format!("Failed to serialize transaction: {}", e))?;
+    let path = offline_tx_path();
+    fs::write(&path, bytes).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+
+    println!("Wrote unsigned transfer of {} lamports to {} at {}", lamports, destination, path);
+    Ok(())
+}
+
+/// Load the transaction written by `build_offline_transfer`, sign the slot
+/// belonging to the configured authority, and write it back in place. Meant
+/// to run on the air-gapped machine holding the authority keypair (or
+/// connected to its remote/hardware wallet) with no RPC access at all.
+fn sign_offline_transaction(config: &Config) -> Result<(), String> {
+    let path = offline_tx_path();
+    let bytes = fs::read(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let mut transaction: Transaction = bincode::deserialize(&bytes)
+        .map_err(|e| format!("Failed to deserialize transaction from {}: {}", path, e))?;
+
+    let authority = load_authority_signer(config)?;
+    transaction
+        .try_partial_sign(&[authority.as_ref()], transaction.message.recent_blockhash)
+        .map_err(|e| format!("Failed to sign transaction: {}", e))?;
+
+    let bytes = bincode::serialize(&transaction)
+        .map_err(|e| format!("Failed to serialize signed transaction: {}", e))?;
+    fs::write(&path, bytes).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+
+    println!("Signed transaction written to {}", path);
+    Ok(())
+}
+
+/// Submit the fully-signed transaction written by `--sign-offline`, using
+/// the same bounded retry helper (`send_with_retries`) as wrap/unwrap and
+/// `send_batch`.
+fn broadcast_offline_transaction(config: &Config) -> Result<(), String> {
+    let path = offline_tx_path();
+    let bytes = fs::read(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let transaction: Transaction = bincode::deserialize(&bytes)
+        .map_err(|e| format!("Failed to deserialize transaction from {}: {}", path, e))?;
+
+    let signature = send_with_retries(|| {
+        config.app_state.rpc_client.send_and_confirm_transaction(&transaction)
+            .map_err(|e| format!("Failed to broadcast offline transaction: {}", e))
+    })?;
+
+    println!("✅ Broadcast offline transaction, signature: {}", signature);
+    Ok(())
+}
+
+/// Prepend a `set_compute_unit_price` instruction pinned to `price`
+/// micro-lamports per CU. Used by `with_priority_fee` once it has settled
+/// on a price.
+fn with_compute_unit_price(mut instructions: Vec<Instruction>, price: u64) -> Vec<Instruction> {
+    let mut prefixed = Vec::with_capacity(instructions.len() + 1);
+    prefixed.push(ComputeBudgetInstruction::set_compute_unit_price(price));
+    prefixed.append(&mut instructions);
+    prefixed
+}
+
+/// Sample a recent per-compute-unit price (micro-lamports) from
+/// `getRecentPrioritizationFees`, used as `with_priority_fee`'s fallback
+/// when `COMPUTE_UNIT_PRICE` isn't pinned explicitly, so transactions still
+/// land reliably under congestion. Returns `None` if the RPC call fails or
+/// returns no samples, in which case the price instruction is simply
+/// omitted.
+fn sample_recent_priority_fee(rpc_client: &anchor_client::solana_client::rpc_client::RpcClient) -> Option<u64> {
+    let fees = rpc_client.get_recent_prioritization_fees(&[]).ok()?;
+    if fees.is_empty() {
+        return None;
+    }
+    let sum: u64 = fees.iter().map(|f| f.prioritization_fee).sum();
+    Some(sum / fees.len() as u64)
+}
+
+/// Prepend compute-budget instructions so transactions land reliably under
+/// congestion instead of silently stalling. `COMPUTE_UNIT_LIMIT` is read
+/// directly; the price prefers `COMPUTE_UNIT_PRICE` (micro-lamports per CU)
+/// when set, otherwise falls back to a value sampled from
+/// `getRecentPrioritizationFees` (see `sample_recent_priority_fee`). Either
+/// source may be unavailable, in which case that instruction is simply
+/// omitted.
+fn with_priority_fee(config: &Config, mut instructions: Vec<Instruction>) -> Vec<Instruction> {
+    if let Some(limit) = std::env::var("COMPUTE_UNIT_LIMIT")
+        .ok()
+        .and_then(|v| v.parse::<u32>().ok())
+    {
+        instructions.insert(0, ComputeBudgetInstruction::set_compute_unit_limit(limit));
+    }
+
+    let price = std::env::var("COMPUTE_UNIT_PRICE")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .or_else(|| sample_recent_priority_fee(&config.app_state.rpc_client));
+
+    match price {
+        Some(price) => with_compute_unit_price(instructions, price),
+        None => instructions,
+    }
+}
+
+/// Maximum number of `close_account`/`transfer` instructions packed into a
+/// single batched transaction. Keeps batches under the 1232-byte packet
+/// limit with comfortable headroom for compute-budget/nonce instructions.
+const MAX_BATCH_INSTRUCTIONS: usize = 10;
+
+/// Maximum number of attempts `poll_get_latest_blockhash` and
+/// `send_with_retries` make before surfacing the underlying RPC error, so a
+/// single transient node hiccup doesn't abort a multi-wallet operation.
+const MAX_RPC_RETRIES: usize = 5;
+
+/// Fetch the latest blockhash, making up to `MAX_RPC_RETRIES` attempts with a
+/// short backoff instead of failing outright on one bad RPC call.
+fn poll_get_latest_blockhash(
+    rpc_client: &anchor_client::solana_client::rpc_client::RpcClient,
+) -> Result<Hash, String> {
+    let logger = solana_vntr_sniper::common::logger::Logger::new("[RPC-RETRY] => ".yellow().to_string());
+    let mut last_err = String::new();
+
+    for attempt in 1..=MAX_RPC_RETRIES {
+        match rpc_client.get_latest_blockhash() {
+            Ok(hash) => return Ok(hash),
+            Err(e) => {
+                last_err = format!("Failed to get recent blockhash: {}", e);
+                logger.log(format!("blockhash fetch attempt {}/{} failed: {}", attempt, MAX_RPC_RETRIES, e).yellow().to_string());
+                if attempt < MAX_RPC_RETRIES {
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                }
+            }
+        }
+    }
+
+    Err(last_err)
+}
+
+/// Run `send_once` (typically `|| rpc_client.send_and_confirm_transaction(&tx)`),
+/// making up to `MAX_RPC_RETRIES` attempts with a short backoff on transient
+/// send/confirm errors instead of failing the whole operation on one dropped
+/// transaction.
+fn send_with_retries<F>(mut send_once: F) -> Result<Signature, String>
+where
+    F: FnMut() -> Result<Signature, String>,
+{
+    let logger = solana_vntr_sniper::common::logger::Logger::new("[RPC-RETRY] => ".yellow().to_string());
+    let mut last_err = String::new();
+
+    for attempt in 1..=MAX_RPC_RETRIES {
+        match send_once() {
+            Ok(signature) => return Ok(signature),
+            Err(e) => {
+                last_err = e;
+                logger.log(format!("send attempt {}/{} failed: {}", attempt, MAX_RPC_RETRIES, last_err).yellow().to_string());
+                if attempt < MAX_RPC_RETRIES {
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                }
+            }
+        }
+    }
+
+    Err(last_err)
+}
+
+/// The address lookup table configured via `ADDRESS_LOOKUP_TABLE`, if any.
+fn configured_lookup_table() -> Option<Pubkey> {
+    std::env::var("ADDRESS_LOOKUP_TABLE")
+        .ok()
+        .and_then(|v| Pubkey::from_str(&v).ok())
+}
+
+/// Extend the configured address lookup table with any of `addresses` it
+/// doesn't already contain. No-ops when `ADDRESS_LOOKUP_TABLE` isn't set.
+/// Creating a fresh table is an operator action (`solana address-lookup-table
+/// create`) rather than something done implicitly on every batch.
+fn extend_lookup_table_if_needed(config: &Config, addresses: &[Pubkey]) -> Result<(), String> {
+    let Some(lookup_table_pubkey) = configured_lookup_table() else {
+        return Ok(());
+    };
+
+    let lookup_table_account = config
+        .app_state
+        .rpc_client
+        .get_account(&lookup_table_pubkey)
+        .map_err(|e| format!("Failed to fetch lookup table {}: {}", lookup_table_pubkey, e))?;
+    let lookup_table = AddressLookupTable::deserialize(&lookup_table_account.data)
+        .map_err(|e| format!("Failed to deserialize lookup table {}: {}", lookup_table_pubkey, e))?;
+
+    let new_addresses: Vec<Pubkey> = addresses
+        .iter()
+        .filter(|a| !lookup_table.addresses.contains(a))
+        .cloned()
+        .collect();
+
+    if new_addresses.is_empty() {
+        return Ok(());
+    }
+
+    let authority_pubkey = config
+        .app_state
+        .wallet
+        .try_pubkey()
+        .map_err(|_| "Failed to get wallet pubkey".to_string())?;
+
+    let extend_instruction = solana_address_lookup_table_program::instruction::extend_lookup_table(
+        lookup_table_pubkey,
+        authority_pubkey,
+        Some(authority_pubkey),
+        new_addresses,
+    );
+
+    send_batch(config, &authority_pubkey, &[&config.app_state.wallet], vec![extend_instruction])
+        .map(|_| ())
+}
+
+/// Build a transaction from `instructions`, signed by `signers` against
+/// `recent_blockhash`. When `ADDRESS_LOOKUP_TABLE` is configured, this
+/// compiles a v0 message referencing that table so packing many
+/// `close_account`/`transfer` instructions into one transaction isn't capped
+/// by the legacy per-transaction account-key limit; otherwise it falls back
+/// to a plain legacy transaction. Either way the result is a
+/// `VersionedTransaction`, so callers don't need to branch on which kind
+/// they got back. Shared by `send_batch` and `TransactionExecutor`.
+fn build_signed_transaction(
+    rpc_client: &anchor_client::solana_client::rpc_client::RpcClient,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    instructions: &[Instruction],
+    recent_blockhash: Hash,
+) -> Result<VersionedTransaction, String> {
+    if let Some(lookup_table_pubkey) = configured_lookup_table() {
+        let lookup_table_account = rpc_client
+            .get_account(&lookup_table_pubkey)
+            .map_err(|e| format!("Failed to fetch lookup table {}: {}", lookup_table_pubkey, e))?;
+
+        let lookup_table = AddressLookupTable::deserialize(&lookup_table_account.data)
+            .map_err(|e| format!("Failed to deserialize lookup table {}: {}", lookup_table_pubkey, e))?;
+
+        let alt_account = AddressLookupTableAccount {
+            key: lookup_table_pubkey,
+            addresses: lookup_table.addresses.to_vec(),
+        };
+
+        let message = v0::Message::try_compile(payer, instructions, &[alt_account], recent_blockhash)
+            .map_err(|e| format!("Failed to compile v0 message: {}", e))?;
+
+        VersionedTransaction::try_new(VersionedMessage::V0(message), signers)
+            .map_err(|e| format!("Failed to sign versioned transaction: {}", e))
+    } else {
+        let transaction = Transaction::new_signed_with_payer(instructions, Some(payer), signers, recent_blockhash);
+        Ok(VersionedTransaction::from(transaction))
+    }
+}
+
+/// Build and send a batch of instructions as a single transaction.
+///
+/// When `ADDRESS_LOOKUP_TABLE` is configured, the batch is sent as a v0
+/// `VersionedTransaction` referencing that table, so packing many
+/// `close_account`/`transfer` instructions into one transaction isn't capped
+/// by the legacy per-transaction account-key limit. Otherwise it falls back
+/// to a plain legacy `Transaction`.
+fn send_batch(
+    config: &Config,
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    instructions: Vec<Instruction>,
+) -> Result<Signature, String> {
+    let instructions = with_priority_fee(config, instructions);
+    let (instructions, recent_blockhash) = prepare_blockhash(config, payer, instructions)?;
+    let transaction = build_signed_transaction(&config.app_state.rpc_client, payer, signers, &instructions, recent_blockhash)?;
+
+    send_with_retries(|| {
+        config
+            .app_state
+            .rpc_client
+            .send_and_confirm_transaction(&transaction)
+            .map_err(|e| format!("Failed to send batched transaction: {}", e))
+    })
+}
+
+/// Resolve the signer that authorizes main-wallet transactions.
+///
+/// When `MAIN_WALLET_REMOTE_URL` is set (e.g. `usb://ledger?key=0`), the
+/// authority is a hardware/remote wallet reached through a
+/// `RemoteWalletManager`, so the main wallet holding real funds never needs
+/// its private key to sit in a plaintext file. Otherwise this falls back to
+/// the in-file `Keypair` loaded by `Config`, matching the previous behavior.
+fn load_authority_signer(config: &Config) -> Result<Box<dyn Signer + Send + Sync>, String> {
+    if let Ok(remote_url) = std::env::var("MAIN_WALLET_REMOTE_URL") {
+        let locator = Locator::new_from_path(&remote_url)
+            .map_err(|e| format!("Invalid remote wallet URL {}: {}", remote_url, e))?;
+
+        let wallet_manager = initialize_wallet_manager()
+            .map_err(|e| format!("Failed to initialize remote wallet manager: {}", e))?;
+
+        let remote_keypair = generate_remote_keypair(
+            locator,
+            DerivationPath::default(),
+            &wallet_manager,
+            false,
+            "icmptunnel wallet manager",
+        ).map_err(|e| format!("Failed to connect to remote signer {}: {}", remote_url, e))?;
+
+        Ok(Box::new(remote_keypair))
+    } else {
+        Ok(Box::new(config.app_state.wallet.clone()))
+    }
+}
+
+/// A transaction tracked by `TransactionExecutor` from submission through
+/// confirmation. Kept around (rather than discarded after the initial send)
+/// so it can be rebuilt against a fresh blockhash and resubmitted if it
+/// expires before landing.
+struct TrackedTx {
+    payer: Pubkey,
+    instructions: Vec<Instruction>,
+    signer: Arc<dyn Signer + Send + Sync>,
+    signature: Signature,
+    last_valid_block_height: u64,
+}
+
+/// Final state of a transaction queued with `TransactionExecutor::push`.
+enum TxOutcome {
+    Confirmed(Signature),
+    Dropped(String),
+}
+
+/// Executor that fires transactions without blocking the caller on
+/// confirmation (`push` signs and sends on the caller's task), then drains
+/// `get_signature_statuses` on a single background polling task, marking each tracked transaction confirmed/dropped/expired and
+/// re-signing against a fresh blockhash on expiry. Used by
+/// `collect_sol`/`distribute_sol` to sweep many wallets concurrently instead
+/// of one transaction (and one fixed `sleep`) at a time.
+struct TransactionExecutor {
+    rpc_client: Arc<anchor_client::solana_client::rpc_client::RpcClient>,
+    next_id: std::sync::atomic::AtomicU64,
+    pending: Arc<std::sync::Mutex<std::collections::HashMap<u64, TrackedTx>>>,
+    outcomes: Arc<std::sync::Mutex<std::collections::HashMap<u64, TxOutcome>>>,
+    poll_handle: tokio::task::JoinHandle<()>,
+}
+
+impl TransactionExecutor {
+    /// Spawn the executor's background confirmation-polling loop.
+    fn new(rpc_client: Arc<anchor_client::solana_client::rpc_client::RpcClient>) -> Self {
+        let pending: Arc<std::sync::Mutex<std::collections::HashMap<u64, TrackedTx>>> =
+            Arc::new(std::sync::Mutex::new(std::collections::HashMap::new()));
+        let outcomes: Arc<std::sync::Mutex<std::collections::HashMap<u64, TxOutcome>>> =
+            Arc::new(std::sync::Mutex::new(std::collections::HashMap::new()));
+
+        let poll_handle = tokio::spawn(Self::poll_loop(rpc_client.clone(), pending.clone(), outcomes.clone()));
+
+        Self {
+            rpc_client,
+            next_id: std::sync::atomic::AtomicU64::new(0),
+            pending,
+            outcomes,
+            poll_handle,
+        }
+    }
+
+    /// Sign `instructions` with `signer` against the latest blockhash, fire
+    /// it with `send_transaction` (which doesn't wait for confirmation), and
+    /// start tracking it. Returns the id used to look the result up later.
+    /// `signer` is an `Arc` rather than an owned `Keypair` so the same main
+    /// wallet authority can back many concurrently pushed transactions.
+    fn push(&self, payer: Pubkey, instructions: Vec<Instruction>, signer: Arc<dyn Signer + Send + Sync>) -> Result<u64, String> {
+        let (blockhash, last_valid_block_height) = self
+            .rpc_client
+            .get_latest_blockhash_with_commitment(anchor_client::solana_sdk::commitment_config::CommitmentConfig::confirmed())
+            .map_err(|e| format!("Failed to get recent blockhash: {}", e))?;
+
+        let transaction = build_signed_transaction(&self.rpc_client, &payer, &[signer.as_ref()], &instructions, blockhash)?;
+
+        let signature = self
+            .rpc_client
+            .send_transaction(&transaction)
+            .map_err(|e| format!("Failed to submit transaction: {}", e))?;
+
+        let id = self.next_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.pending.lock().unwrap().insert(id, TrackedTx {
+            payer,
+            instructions,
+            signer,
+            signature,
+            last_valid_block_height,
+        });
+
+        Ok(id)
+    }
+
+    /// Background loop: every 2 seconds, check the status of every pending
+    /// signature. Confirmed/failed ones move to `outcomes`; ones whose
+    /// blockhash has since aged out of validity are re-signed against a
+    /// fresh blockhash and resubmitted rather than given up on.
+    async fn poll_loop(
+        rpc_client: Arc<anchor_client::solana_client::rpc_client::RpcClient>,
+        pending: Arc<std::sync::Mutex<std::collections::HashMap<u64, TrackedTx>>>,
+        outcomes: Arc<std::sync::Mutex<std::collections::HashMap<u64, TxOutcome>>>,
+    ) {
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+
+            let ids_and_signatures: Vec<(u64, Signature)> = {
+                let pending = pending.lock().unwrap();
+                if pending.is_empty() {
+                    continue;
+                }
+                pending.iter().map(|(id, tracked)| (*id, tracked.signature)).collect()
+            };
+
+            let signatures: Vec<Signature> = ids_and_signatures.iter().map(|(_, sig)| *sig).collect();
+            let statuses = match rpc_client.get_signature_statuses(&signatures) {
+                Ok(response) => response.value,
+                Err(_) => continue,
+            };
+
+            let current_block_height = rpc_client.get_block_height().ok();
+
+            for ((id, signature), status) in ids_and_signatures.into_iter().zip(statuses) {
+                match status {
+                    Some(status) => {
+                        let tracked = pending.lock().unwrap().remove(&id);
+                        if tracked.is_none() {
+                            continue;
+                        }
+                        let outcome = match status.err {
+                            Some(err) => TxOutcome::Dropped(format!("Transaction {} failed: {}", signature, err)),
+                            None => TxOutcome::Confirmed(signature),
+                        };
+                        outcomes.lock().unwrap().insert(id, outcome);
+                    }
+                    None => {
+                        let expired = current_block_height
+                            .map(|height| {
+                                let tracked = pending.lock().unwrap();
+                                tracked.get(&id).is_some_and(|t| height > t.last_valid_block_height)
+                            })
+                            .unwrap_or(false);
+
+                        if !expired {
+                            continue;
+                        }
+
+                        let mut pending = pending.lock().unwrap();
+                        let Some(tracked) = pending.get_mut(&id) else {
+                            continue;
+                        };
+
+                        let Ok((blockhash, last_valid_block_height)) = rpc_client
+                            .get_latest_blockhash_with_commitment(anchor_client::solana_sdk::commitment_config::CommitmentConfig::confirmed())
+                        else {
+                            continue;
+                        };
+
+                        let Ok(transaction) = build_signed_transaction(
+                            &rpc_client,
+                            &tracked.payer,
+                            &[tracked.signer.as_ref()],
+                            &tracked.instructions,
+                            blockhash,
+                        ) else {
+                            continue;
+                        };
+
+                        match rpc_client.send_transaction(&transaction) {
+                            Ok(new_signature) => {
+                                tracked.signature = new_signature;
+                                tracked.last_valid_block_height = last_valid_block_height;
+                            }
+                            Err(_) => continue,
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Block until every pushed transaction has an outcome (confirmed or
+    /// dropped) and return them all, keyed by the id `push` returned.
+    async fn join(&self) -> std::collections::HashMap<u64, TxOutcome> {
+        loop {
+            if self.pending.lock().unwrap().is_empty() {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+        }
+
+        std::mem::take(&mut *self.outcomes.lock().unwrap())
+    }
+
+    /// Drop every transaction still awaiting confirmation without waiting
+    /// for it, so a cancelled sweep doesn't hang on stragglers.
+    fn cancel_pending(&self) {
+        self.pending.lock().unwrap().clear();
+    }
+}
+
+impl Drop for TransactionExecutor {
+    fn drop(&mut self) {
+        self.poll_handle.abort();
+    }
+}
 
 /// Generate wallets and save them to ./wallet directory
 async fn generate_wallets() -> Result<(), String> {
@@ -102,9 +785,10 @@ async fn initialize_token_account_list(config: &Config) {
 /// Wrap SOL to Wrapped SOL (WSOL)
 async fn wrap_sol(config: &Config, amount: f64) -> Result<(), String> {
     let logger = solana_vntr_sniper::common::logger::Logger::new("[WRAP-SOL] => ".green().to_string());
-    
-    // Get wallet pubkey
-    let wallet_pubkey = match config.app_state.wallet.try_pubkey() {
+
+    // Resolve the authorizing signer (in-file keypair or remote/hardware wallet)
+    let authority = load_authority_signer(config)?;
+    let wallet_pubkey = match authority.try_pubkey() {
         Ok(pk) => pk,
         Err(_) => return Err("Failed to get wallet pubkey".to_string()),
     };
@@ -138,24 +822,27 @@ async fn wrap_sol(config: &Config, amount: f64) -> Result<(), String> {
         ).map_err(|e| format!("Failed to create sync native instruction: {}", e))?
     );
     
-    // Send transaction
-    let recent_blockhash = config.app_state.rpc_client.get_latest_blockhash()
-        .map_err(|e| format!("Failed to get recent blockhash: {}", e))?;
-    
+    // Send transaction (honors a configured durable nonce, see `prepare_blockhash`)
+    let instructions = with_priority_fee(config, instructions);
+    let (instructions, recent_blockhash) = prepare_blockhash(config, &wallet_pubkey, instructions)?;
+
     let transaction = Transaction::new_signed_with_payer(
         &instructions,
         Some(&wallet_pubkey),
-        &[&config.app_state.wallet],
+        &[authority.as_ref()],
         recent_blockhash,
     );
-    
-    match config.app_state.rpc_client.send_and_confirm_transaction(&transaction) {
+
+    match send_with_retries(|| {
+        config.app_state.rpc_client.send_and_confirm_transaction(&transaction)
+            .map_err(|e| format!("Failed to wrap SOL: {}", e))
+    }) {
         Ok(signature) => {
             logger.log(format!("SOL wrapped successfully, signature: {}", signature));
             Ok(())
         },
         Err(e) => {
-            Err(format!("Failed to wrap SOL: {}", e))
+            Err(e)
         }
     }
 }
@@ -163,9 +850,10 @@ async fn wrap_sol(config: &Config, amount: f64) -> Result<(), String> {
 /// Unwrap SOL from Wrapped SOL (WSOL) account
 async fn unwrap_sol(config: &Config) -> Result<(), String> {
     let logger = solana_vntr_sniper::common::logger::Logger::new("[UNWRAP-SOL] => ".green().to_string());
-    
-    // Get wallet pubkey
-    let wallet_pubkey = match config.app_state.wallet.try_pubkey() {
+
+    // Resolve the authorizing signer (in-file keypair or remote/hardware wallet)
+    let authority = load_authority_signer(config)?;
+    let wallet_pubkey = match authority.try_pubkey() {
         Ok(pk) => pk,
         Err(_) => return Err("Failed to get wallet pubkey".to_string()),
     };
@@ -197,82 +885,107 @@ async fn unwrap_sol(config: &Config) -> Result<(), String> {
         &[&wallet_pubkey],
     ).map_err(|e| format!("Failed to create close account instruction: {}", e))?;
     
-    // Send transaction
-    let recent_blockhash = config.app_state.rpc_client.get_latest_blockhash()
-        .map_err(|e| format!("Failed to get recent blockhash: {}", e))?;
-    
+    // Send transaction (honors a configured durable nonce, see `prepare_blockhash`)
+    let (instructions, recent_blockhash) = prepare_blockhash(config, &wallet_pubkey, with_priority_fee(config, vec![close_instruction]))?;
+
     let transaction = Transaction::new_signed_with_payer(
-        &[close_instruction],
+        &instructions,
         Some(&wallet_pubkey),
-        &[&config.app_state.wallet],
+        &[authority.as_ref()],
         recent_blockhash,
     );
-    
-    match config.app_state.rpc_client.send_and_confirm_transaction(&transaction) {
+
+    match send_with_retries(|| {
+        config.app_state.rpc_client.send_and_confirm_transaction(&transaction)
+            .map_err(|e| format!("Failed to unwrap WSOL: {}", e))
+    }) {
         Ok(signature) => {
             logger.log(format!("WSOL unwrapped successfully, signature: {}", signature));
             Ok(())
         },
         Err(e) => {
-            Err(format!("Failed to unwrap WSOL: {}", e))
+            Err(e)
         }
     }
 }
 
+/// List every SPL token account owned by `owner`, already unpacked, in one
+/// `getProgramAccounts` round-trip. Replaces the `get_token_accounts_by_owner`
+/// + one `get_account` per result pattern used elsewhere in this file: the
+/// `DataSize`/owner `Memcmp` filter pair narrows the scan server-side to just
+/// this wallet's token accounts, and requesting `Base64` encoding returns
+/// account data we can unpack directly instead of re-fetching it.
+fn scan_owned_token_accounts(
+    rpc_client: &anchor_client::solana_client::rpc_client::RpcClient,
+    owner: &Pubkey,
+) -> Result<Vec<(Pubkey, spl_token::state::Account)>, String> {
+    let token_program = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap();
+
+    let accounts = rpc_client.get_program_accounts_with_config(
+        &token_program,
+        RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(165),
+                RpcFilterType::Memcmp(Memcmp {
+                    offset: 32,
+                    bytes: MemcmpEncodedBytes::Base58(owner.to_string()),
+                    encoding: None,
+                }),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            with_context: None,
+        },
+    ).map_err(|e| format!("Failed to scan token accounts for {}: {}", owner, e))?;
+
+    Ok(accounts
+        .into_iter()
+        .filter_map(|(pubkey, account)| {
+            spl_token::state::Account::unpack(&account.data)
+                .ok()
+                .map(|token_account| (pubkey, token_account))
+        })
+        .collect())
+}
+
 /// Close all token accounts owned by the wallet
-async fn close_all_token_accounts(config: &Config) -> Result<(), String> {
+async fn close_all_token_accounts(config: &Config, output: OutputFormat) -> Result<(), String> {
     let logger = solana_vntr_sniper::common::logger::Logger::new("[CLOSE-TOKEN-ACCOUNTS] => ".green().to_string());
-    
-    // Get wallet pubkey
-    let wallet_pubkey = match config.app_state.wallet.try_pubkey() {
+
+    // Resolve the authorizing signer (in-file keypair or remote/hardware wallet)
+    let authority = load_authority_signer(config)?;
+    let wallet_pubkey = match authority.try_pubkey() {
         Ok(pk) => pk,
         Err(_) => return Err("Failed to get wallet pubkey".to_string()),
     };
     
-    // Get the token program pubkey
-    let token_program = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap();
-    
-    // Query all token accounts owned by the wallet
-    let accounts = config.app_state.rpc_client.get_token_accounts_by_owner(
-        &wallet_pubkey,
-        anchor_client::solana_client::rpc_request::TokenAccountsFilter::ProgramId(token_program)
-    ).map_err(|e| format!("Failed to get token accounts: {}", e))?;
-    
+    // Query all token accounts owned by the wallet in one round-trip
+    let accounts = scan_owned_token_accounts(&config.app_state.rpc_client, &wallet_pubkey)?;
+
     if accounts.is_empty() {
         logger.log("No token accounts found to close".to_string());
         return Ok(());
     }
-    
+
     logger.log(format!("Found {} token accounts to close", accounts.len()));
-    
-    let mut closed_count = 0;
-    let mut failed_count = 0;
-    
-    // Close each token account
-    for account_info in accounts {
-        let token_account = Pubkey::from_str(&account_info.pubkey)
-            .map_err(|_| format!("Invalid token account pubkey: {}", account_info.pubkey))?;
-        
+
+    let mut close_instructions = Vec::new();
+    let mut skipped_count = 0;
+
+    // Build a close instruction for every eligible account up front, so they
+    // can be packed many-per-transaction instead of one round-trip each.
+    for (token_account, token_data) in accounts {
         // Skip WSOL accounts with non-zero balance (these need to be unwrapped first)
-        let account_data = match config.app_state.rpc_client.get_account(&token_account) {
-            Ok(data) => data,
-            Err(e) => {
-                logger.log(format!("Failed to get account data for {}: {}", token_account, e).red().to_string());
-                failed_count += 1;
-                continue;
-            }
-        };
-        
-        // Check if this is a WSOL account with balance
-        if let Ok(token_data) = spl_token::state::Account::unpack(&account_data.data) {
-            if token_data.mint == spl_token::native_mint::id() && token_data.amount > 0 {
-                logger.log(format!("Skipping WSOL account with non-zero balance: {} ({})", 
-                                 token_account, 
-                                 token_data.amount as f64 / 1_000_000_000.0));
-                continue;
-            }
+        if token_data.mint == spl_token::native_mint::id() && token_data.amount > 0 {
+            logger.log(format!("Skipping WSOL account with non-zero balance: {} ({})",
+                             token_account,
+                             token_data.amount as f64 / 1_000_000_000.0));
+            skipped_count += 1;
+            continue;
         }
-        
+
         // Create close instruction
         let close_instruction = token::close_account(
             wallet_pubkey,
@@ -281,32 +994,69 @@ async fn close_all_token_accounts(config: &Config) -> Result<(), String> {
             wallet_pubkey,
             &[&wallet_pubkey],
         ).map_err(|e| format!("Failed to create close instruction for {}: {}", token_account, e))?;
-        
-        // Send transaction
-        let recent_blockhash = config.app_state.rpc_client.get_latest_blockhash()
-            .map_err(|e| format!("Failed to get recent blockhash: {}", e))?;
-        
-        let transaction = Transaction::new_signed_with_payer(
-            &[close_instruction],
-            Some(&wallet_pubkey),
-            &[&config.app_state.wallet],
-            recent_blockhash,
-        );
-        
-        match config.app_state.rpc_client.send_and_confirm_transaction(&transaction) {
+
+        close_instructions.push((token_account, close_instruction));
+    }
+
+    let mut closed_count = 0;
+    let mut failed_count = 0;
+    let mut results = Vec::new();
+
+    let account_pubkeys: Vec<Pubkey> = close_instructions.iter().map(|(pubkey, _)| *pubkey).collect();
+    extend_lookup_table_if_needed(config, &account_pubkeys)?;
+
+    // Pack up to MAX_BATCH_INSTRUCTIONS close instructions into each
+    // transaction (see `send_batch`), so a wallet with dozens of accounts
+    // closes in a handful of round-trips instead of one per account.
+    for batch in close_instructions.chunks(MAX_BATCH_INSTRUCTIONS) {
+        let token_accounts: Vec<Pubkey> = batch.iter().map(|(pubkey, _)| *pubkey).collect();
+        let instructions: Vec<Instruction> = batch.iter().map(|(_, ix)| ix.clone()).collect();
+        let wallet_field = token_accounts.iter().map(|a| a.to_string()).collect::<Vec<_>>().join(",");
+
+        match send_batch(config, &wallet_pubkey, &[authority.as_ref()], instructions) {
             Ok(signature) => {
-                logger.log(format!("Closed token account {}, signature: {}", token_account, signature));
-                closed_count += 1;
+                logger.log(format!(
+                    "Closed {} token accounts {:?}, signature: {}",
+                    token_accounts.len(), token_accounts, signature
+                ));
+                closed_count += token_accounts.len();
+                results.push(WalletOpResult {
+                    wallet: wallet_field,
+                    lamports: 0,
+                    signature: Some(signature.to_string()),
+                    success: true,
+                    error: None,
+                });
             },
             Err(e) => {
-                logger.log(format!("Failed to close token account {}: {}", token_account, e).red().to_string());
-                failed_count += 1;
+                logger.log(format!(
+                    "Failed to close batch of {} token accounts: {}", token_accounts.len(), e
+                ).red().to_string());
+                failed_count += token_accounts.len();
+                results.push(WalletOpResult {
+                    wallet: wallet_field,
+                    lamports: 0,
+                    signature: None,
+                    success: false,
+                    error: Some(e),
+                });
             }
         }
     }
-    
+
+    if skipped_count > 0 {
+        logger.log(format!("Skipped {} WSOL accounts with non-zero balance", skipped_count));
+    }
+
     logger.log(format!("Closed {} token accounts, {} failed", closed_count, failed_count));
-    
+
+    output.emit(&CommandSummary {
+        command: "close".to_string(),
+        succeeded: results.iter().filter(|r| r.success).count(),
+        failed: results.iter().filter(|r| !r.success).count(),
+        results,
+    });
+
     if failed_count > 0 {
         Err(format!("Failed to close {} token accounts", failed_count))
     } else {
@@ -412,16 +1162,91 @@ fn load_all_wallets() -> Result<Vec<Arc<Keypair>>, String> {
     Ok(wallets)
 }
 
+/// Request devnet/testnet SOL for the main wallet (and optionally every
+/// generated wallet), so a fresh `--wallet` set can be funded for
+/// market-making tests without leaving the tool.
+///
+/// Refuses to run against mainnet: `request_airdrop` fails there anyway,
+/// but checking the RPC URL up front gives a clear error instead of a
+/// confusing faucet rejection.
+async fn airdrop_sol(config: &Config) -> Result<(), String> {
+    let logger = solana_vntr_sniper::common::logger::Logger::new("[AIRDROP] => ".cyan().to_string());
+
+    let rpc_url = config.app_state.rpc_client.url();
+    if rpc_url.contains("mainnet") {
+        return Err("Refusing to airdrop against a mainnet RPC endpoint".to_string());
+    }
+
+    let amount_sol = std::env::var("AIRDROP_AMOUNT")
+        .ok()
+        .and_then(|v| v.parse::<f64>().ok())
+        .unwrap_or(1.0);
+    let lamports = ui_amount_to_amount(amount_sol, 9);
+
+    let airdrop_all_wallets = std::env::var("AIRDROP_ALL_WALLETS")
+        .ok()
+        .and_then(|v| v.parse::<bool>().ok())
+        .unwrap_or(false);
+
+    let main_wallet_pubkey = config.app_state.wallet.pubkey();
+    request_and_confirm_airdrop(config, &logger, &main_wallet_pubkey, lamports).await?;
+
+    if airdrop_all_wallets {
+        let wallets = load_all_wallets()?;
+        logger.log(format!("Airdropping {} SOL to {} generated wallets...", amount_sol, wallets.len()));
+
+        for (i, wallet) in wallets.iter().enumerate() {
+            let wallet_pubkey = wallet.pubkey();
+            if let Err(e) = request_and_confirm_airdrop(config, &logger, &wallet_pubkey, lamports).await {
+                logger.log(format!("❌ Failed to airdrop to wallet {}: {}", i + 1, e).red().to_string());
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+        }
+    }
+
+    Ok(())
+}
+
+/// Request an airdrop for `pubkey` and poll until the RPC reports the
+/// signature as confirmed.
+async fn request_and_confirm_airdrop(
+    config: &Config,
+    logger: &solana_vntr_sniper::common::logger::Logger,
+    pubkey: &Pubkey,
+    lamports: u64,
+) -> Result<(), String> {
+    let signature = config
+        .app_state
+        .rpc_client
+        .request_airdrop(pubkey, lamports)
+        .map_err(|e| format!("Failed to request airdrop for {}: {}", pubkey, e))?;
+
+    logger.log(format!("Requested airdrop for {}, signature: {}", pubkey, signature));
+
+    for _ in 0..30 {
+        if let Ok(true) = config.app_state.rpc_client.confirm_transaction(&signature) {
+            logger.log(format!("✅ Airdrop confirmed for {}", pubkey));
+            return Ok(());
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+    }
+
+    Err(format!("Timed out waiting for airdrop confirmation for {}", pubkey))
+}
+
 /// Distribute SOL from main wallet to all generated wallets and convert to WSOL
-async fn distribute_sol(config: &Config) -> Result<(), String> {
+async fn distribute_sol(config: &Config, output: OutputFormat) -> Result<(), String> {
     let logger = solana_vntr_sniper::common::logger::Logger::new("[DISTRIBUTE] => ".yellow().bold().to_string());
     
     // Load all wallets
     let wallets = load_all_wallets()?;
     logger.log(format!("Found {} wallets to distribute to", wallets.len()));
-    
+
+    // Resolve the authorizing signer (in-file keypair or remote/hardware wallet)
+    let authority = load_authority_signer(config)?;
+
     // Get main wallet balance
-    let main_wallet_pubkey = config.app_state.wallet.pubkey();
+    let main_wallet_pubkey = authority.pubkey();
     let main_balance = config.app_state.rpc_client.get_balance(&main_wallet_pubkey)
         .map_err(|e| format!("Failed to get main wallet balance: {}", e))?;
     
@@ -438,57 +1263,64 @@ async fn distribute_sol(config: &Config) -> Result<(), String> {
     
     let amount_per_wallet = distributable_amount / wallets.len() as f64;
     logger.log(format!("Distributing {} SOL to each wallet", amount_per_wallet));
-    
-    // Distribute SOL to each wallet
+
+    // Queue every per-wallet transfer through a `TransactionExecutor` instead
+    // of sending one at a time with a fixed sleep in between, so sweeping
+    // many wallets isn't bottlenecked on round-trip confirmation latency.
+    let authority: Arc<dyn Signer + Send + Sync> = Arc::from(authority);
+    let executor = TransactionExecutor::new(config.app_state.rpc_client.clone());
+    let mut queued = Vec::new();
+
     for (i, wallet) in wallets.iter().enumerate() {
         let wallet_pubkey = wallet.pubkey();
         let lamports_to_send = (amount_per_wallet * 1_000_000_000.0) as u64;
-        
-        logger.log(format!("Distributing {} SOL to wallet {}: {}", 
-                          amount_per_wallet, i + 1, wallet_pubkey));
-        
-        // Create transfer instruction
-        let transfer_instruction = system_instruction::transfer(
-            &main_wallet_pubkey,
-            &wallet_pubkey,
-            lamports_to_send,
-        );
-        
-        // Send transaction
-        let recent_blockhash = config.app_state.rpc_client.get_latest_blockhash()
-            .map_err(|e| format!("Failed to get recent blockhash: {}", e))?;
-        
-        let transaction = Transaction::new_signed_with_payer(
-            &[transfer_instruction],
-            Some(&main_wallet_pubkey),
-            &[&config.app_state.wallet],
-            recent_blockhash,
-        );
-        
-        match config.app_state.rpc_client.send_and_confirm_transaction(&transaction) {
-            Ok(signature) => {
-                logger.log(format!("✅ Distributed {} SOL to wallet {}, signature: {}", 
-                                  amount_per_wallet, i + 1, signature));
-            },
+
+        let transfer_instruction = system_instruction::transfer(&main_wallet_pubkey, &wallet_pubkey, lamports_to_send);
+        let instructions = with_priority_fee(config, vec![transfer_instruction]);
+
+        match executor.push(main_wallet_pubkey, instructions, authority.clone()) {
+            Ok(id) => {
+                logger.log(format!("Queued distribution of {} SOL to wallet {}: {}", amount_per_wallet, i + 1, wallet_pubkey));
+                queued.push((id, wallet_pubkey, lamports_to_send));
+            }
             Err(e) => {
-                logger.log(format!("❌ Failed to distribute to wallet {}: {}", i + 1, e).red().to_string());
-                continue;
+                logger.log(format!("❌ Failed to queue distribution to wallet {}: {}", i + 1, e).red().to_string());
             }
         }
-        
-        // Wait a bit to avoid rate limiting
-        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
     }
-    
-    // Now convert 75% of SOL to WSOL in each wallet
+
+    let mut outcomes = executor.join().await;
+    let mut results = Vec::new();
+
+    for (id, wallet_pubkey, lamports_to_send) in queued {
+        let (success, signature, error) = match outcomes.remove(&id) {
+            Some(TxOutcome::Confirmed(signature)) => {
+                logger.log(format!("✅ Distributed SOL to wallet {}, signature: {}", wallet_pubkey, signature));
+                (true, Some(signature.to_string()), None)
+            }
+            Some(TxOutcome::Dropped(e)) => {
+                logger.log(format!("❌ {}", e).red().to_string());
+                (false, None, Some(e))
+            }
+            None => {
+                let e = format!("No confirmation outcome recorded for wallet {}", wallet_pubkey);
+                logger.log(e.clone().red().to_string());
+                (false, None, Some(e))
+            }
+        };
+        results.push(WalletOpResult { wallet: wallet_pubkey.to_string(), lamports: lamports_to_send, signature, success, error });
+    }
+
+    // Now convert 75% of SOL to WSOL in each wallet, again queued concurrently.
     logger.log("Converting 75% of SOL to WSOL in each wallet...".to_string());
-    
+
+    let conversion_executor = TransactionExecutor::new(config.app_state.rpc_client.clone());
+    let mut conversion_queued = Vec::new();
+
     for (i, wallet) in wallets.iter().enumerate() {
         let wallet_pubkey = wallet.pubkey();
         let wsol_amount = amount_per_wallet * 0.75; // 75% to WSOL, 25% kept for fees
-        
-        logger.log(format!("Converting {} SOL to WSOL for wallet {}", wsol_amount, i + 1));
-        
+
         // Create WSOL account instructions
         let (wsol_account, mut instructions) = match token::create_wsol_account(wallet_pubkey) {
             Ok(result) => result,
@@ -497,219 +1329,331 @@ async fn distribute_sol(config: &Config) -> Result<(), String> {
                 continue;
             }
         };
-        
+
         // Convert to lamports
         let lamports = (wsol_amount * 1_000_000_000.0) as u64;
-        
+
         // Transfer SOL to the WSOL account
-        instructions.push(
-            system_instruction::transfer(
-                &wallet_pubkey,
-                &wsol_account,
-                lamports,
-            )
-        );
-        
+        instructions.push(system_instruction::transfer(&wallet_pubkey, &wsol_account, lamports));
+
         // Sync native instruction
         instructions.push(
             sync_native(&spl_token::id(), &wsol_account)
                 .map_err(|e| format!("Failed to create sync native instruction: {}", e))?
         );
-        
-        // Send transaction
-        let recent_blockhash = config.app_state.rpc_client.get_latest_blockhash()
-            .map_err(|e| format!("Failed to get recent blockhash: {}", e))?;
-        
-        let transaction = Transaction::new_signed_with_payer(
-            &instructions,
-            Some(&wallet_pubkey),
-            &[wallet],
-            recent_blockhash,
-        );
-        
-        match config.app_state.rpc_client.send_and_confirm_transaction(&transaction) {
-            Ok(signature) => {
-                logger.log(format!("✅ Converted {} SOL to WSOL for wallet {}, signature: {}", 
-                                  wsol_amount, i + 1, signature));
-            },
+
+        let instructions = with_priority_fee(config, instructions);
+        let signer: Arc<dyn Signer + Send + Sync> = Arc::new(wallet.clone());
+
+        match conversion_executor.push(wallet_pubkey, instructions, signer) {
+            Ok(id) => {
+                logger.log(format!("Queued {} SOL -> WSOL conversion for wallet {}", wsol_amount, i + 1));
+                conversion_queued.push((id, wallet_pubkey));
+            }
             Err(e) => {
-                logger.log(format!("❌ Failed to convert SOL to WSOL for wallet {}: {}", i + 1, e).red().to_string());
+                logger.log(format!("❌ Failed to queue WSOL conversion for wallet {}: {}", i + 1, e).red().to_string());
             }
         }
-        
-        // Wait a bit to avoid rate limiting
-        tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
     }
-    
+
+    let mut conversion_outcomes = conversion_executor.join().await;
+    for (id, wallet_pubkey) in conversion_queued {
+        match conversion_outcomes.remove(&id) {
+            Some(TxOutcome::Confirmed(signature)) => {
+                logger.log(format!("✅ Converted SOL to WSOL for wallet {}, signature: {}", wallet_pubkey, signature));
+            }
+            Some(TxOutcome::Dropped(e)) => {
+                logger.log(format!("❌ {}", e).red().to_string());
+            }
+            None => {
+                logger.log(format!("❌ No confirmation outcome recorded for wallet {} WSOL conversion", wallet_pubkey).red().to_string());
+            }
+        }
+    }
+
     logger.log("Distribution and WSOL conversion completed!".green().bold().to_string());
+
+    output.emit(&CommandSummary {
+        command: "distribute".to_string(),
+        succeeded: results.iter().filter(|r| r.success).count(),
+        failed: results.iter().filter(|r| !r.success).count(),
+        results,
+    });
+
     Ok(())
 }
 
 /// Collect all SOL from generated wallets back to main wallet
-async fn collect_sol(config: &Config) -> Result<(), String> {
+async fn collect_sol(config: &Config, output: OutputFormat) -> Result<(), String> {
     let logger = solana_vntr_sniper::common::logger::Logger::new("[COLLECT] => ".red().bold().to_string());
-    
+
     // Load all wallets
     let wallets = load_all_wallets()?;
     logger.log(format!("Found {} wallets to collect from", wallets.len()));
-    
-    let main_wallet_pubkey = config.app_state.wallet.pubkey();
-    let mut total_collected = 0.0;
-    
-    // Get the token program pubkey
-    let token_program = Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap();
-    
+
+    // Resolve the authorizing signer so collected funds land on a
+    // hardware/remote main wallet when one is configured.
+    let authority = load_authority_signer(config)?;
+    let main_wallet_pubkey = authority.pubkey();
+
+    // Phase 1: close every wallet's token accounts, queued concurrently
+    // through a `TransactionExecutor` instead of one batch-per-wallet send
+    // with a fixed sleep between each.
+    let close_executor = TransactionExecutor::new(config.app_state.rpc_client.clone());
+    let mut close_queued = Vec::new();
+
     for (i, wallet) in wallets.iter().enumerate() {
         let wallet_pubkey = wallet.pubkey();
-        logger.log(format!("Processing wallet {}: {}", i + 1, wallet_pubkey));
-        
-        // First, close all token accounts for this wallet
-        match config.app_state.rpc_client.get_token_accounts_by_owner(
-            &wallet_pubkey,
-            anchor_client::solana_client::rpc_request::TokenAccountsFilter::ProgramId(token_program)
-        ) {
-            Ok(token_accounts) => {
-                logger.log(format!("Found {} token accounts for wallet {}", token_accounts.len(), i + 1));
-                
-                for account_info in token_accounts {
-                    let token_account = Pubkey::from_str(&account_info.pubkey)
-                        .map_err(|_| format!("Invalid token account pubkey: {}", account_info.pubkey))?;
-                    
-                    // Get account data to check if it's WSOL with balance
-                    if let Ok(account_data) = config.app_state.rpc_client.get_account(&token_account) {
-                        if let Ok(token_data) = spl_token::state::Account::unpack(&account_data.data) {
-                            // If it's WSOL with balance, unwrap it first
-                            if token_data.mint == spl_token::native_mint::id() && token_data.amount > 0 {
-                                logger.log(format!("Unwrapping WSOL account: {} ({})", 
-                                                 token_account, 
-                                                 token_data.amount as f64 / 1_000_000_000.0));
-                                
-                                // Close the WSOL account to recover SOL
-                                let close_instruction = token::close_account(
-                                    wallet_pubkey,
-                                    token_account,
-                                    wallet_pubkey,
-                                    wallet_pubkey,
-                                    &[&wallet_pubkey],
-                                ).map_err(|e| format!("Failed to create close instruction: {}", e))?;
-                                
-                                let recent_blockhash = config.app_state.rpc_client.get_latest_blockhash()
-                                    .map_err(|e| format!("Failed to get recent blockhash: {}", e))?;
-                                
-                                let transaction = Transaction::new_signed_with_payer(
-                                    &[close_instruction],
-                                    Some(&wallet_pubkey),
-                                    &[wallet],
-                                    recent_blockhash,
-                                );
-                                
-                                match config.app_state.rpc_client.send_and_confirm_transaction(&transaction) {
-                                    Ok(signature) => {
-                                        logger.log(format!("✅ Unwrapped WSOL account {}, signature: {}", 
-                                                          token_account, signature));
-                                    },
-                                    Err(e) => {
-                                        logger.log(format!("❌ Failed to unwrap WSOL account {}: {}", 
-                                                          token_account, e).red().to_string());
-                                    }
-                                }
-                            } else {
-                                // Close other token accounts (should be empty)
-                                let close_instruction = token::close_account(
-                                    wallet_pubkey,
-                                    token_account,
-                                    wallet_pubkey,
-                                    wallet_pubkey,
-                                    &[&wallet_pubkey],
-                                ).map_err(|e| format!("Failed to create close instruction: {}", e))?;
-                                
-                                let recent_blockhash = config.app_state.rpc_client.get_latest_blockhash()
-                                    .map_err(|e| format!("Failed to get recent blockhash: {}", e))?;
-                                
-                                let transaction = Transaction::new_signed_with_payer(
-                                    &[close_instruction],
-                                    Some(&wallet_pubkey),
-                                    &[wallet],
-                                    recent_blockhash,
-                                );
-                                
-                                match config.app_state.rpc_client.send_and_confirm_transaction(&transaction) {
-                                    Ok(signature) => {
-                                        logger.log(format!("✅ Closed token account {}, signature: {}", 
-                                                          token_account, signature));
-                                    },
-                                    Err(e) => {
-                                        logger.log(format!("❌ Failed to close token account {}: {}", 
-                                                          token_account, e).red().to_string());
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    
-                    // Wait a bit to avoid rate limiting
-                    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                }
-            },
+
+        let token_accounts = match scan_owned_token_accounts(&config.app_state.rpc_client, &wallet_pubkey) {
+            Ok(accounts) => accounts,
             Err(e) => {
-                logger.log(format!("❌ Failed to get token accounts for wallet {}: {}", i + 1, e).red().to_string());
+                logger.log(format!("❌ {}", e).red().to_string());
+                continue;
             }
+        };
+
+        if token_accounts.is_empty() {
+            continue;
         }
-        
-        // Now collect all remaining SOL
-        match config.app_state.rpc_client.get_balance(&wallet_pubkey) {
-            Ok(balance) => {
-                if balance > 5000 { // Leave minimum for rent
-                    let balance_to_send = balance - 5000; // Leave 5000 lamports for rent
-                    let balance_sol = balance_to_send as f64 / 1_000_000_000.0;
-                    
-                    logger.log(format!("Collecting {} SOL from wallet {}", balance_sol, i + 1));
-                    
-                    // Create transfer instruction
-                    let transfer_instruction = system_instruction::transfer(
-                        &wallet_pubkey,
-                        &main_wallet_pubkey,
-                        balance_to_send,
-                    );
-                    
-                    // Send transaction
-                    let recent_blockhash = config.app_state.rpc_client.get_latest_blockhash()
-                        .map_err(|e| format!("Failed to get recent blockhash: {}", e))?;
-                    
-                    let transaction = Transaction::new_signed_with_payer(
-                        &[transfer_instruction],
-                        Some(&wallet_pubkey),
-                        &[wallet],
-                        recent_blockhash,
-                    );
-                    
-                    match config.app_state.rpc_client.send_and_confirm_transaction(&transaction) {
-                        Ok(signature) => {
-                            logger.log(format!("✅ Collected {} SOL from wallet {}, signature: {}", 
-                                              balance_sol, i + 1, signature));
-                            total_collected += balance_sol;
-                        },
-                        Err(e) => {
-                            logger.log(format!("❌ Failed to collect from wallet {}: {}", i + 1, e).red().to_string());
-                        }
-                    }
-                } else {
-                    logger.log(format!("Wallet {} has insufficient balance to collect", i + 1));
+        logger.log(format!("Found {} token accounts for wallet {}", token_accounts.len(), i + 1));
+
+        // Build a close instruction for every account up front (closing a
+        // WSOL account recovers its lamports the same way as any other
+        // token account), then pack them many-per-transaction.
+        let mut close_instructions = Vec::new();
+        for (token_account, token_data) in token_accounts {
+            if token_data.mint == spl_token::native_mint::id() && token_data.amount > 0 {
+                logger.log(format!("Unwrapping WSOL account: {} ({})",
+                                 token_account,
+                                 token_data.amount as f64 / 1_000_000_000.0));
+            }
+
+            let close_instruction = token::close_account(
+                wallet_pubkey,
+                token_account,
+                wallet_pubkey,
+                wallet_pubkey,
+                &[&wallet_pubkey],
+            ).map_err(|e| format!("Failed to create close instruction: {}", e))?;
+
+            close_instructions.push((token_account, close_instruction));
+        }
+
+        let account_pubkeys: Vec<Pubkey> = close_instructions.iter().map(|(pubkey, _)| *pubkey).collect();
+        extend_lookup_table_if_needed(config, &account_pubkeys)?;
+
+        let signer: Arc<dyn Signer + Send + Sync> = Arc::new(wallet.clone());
+
+        for batch in close_instructions.chunks(MAX_BATCH_INSTRUCTIONS) {
+            let token_accounts: Vec<Pubkey> = batch.iter().map(|(pubkey, _)| *pubkey).collect();
+            let instructions = with_priority_fee(config, batch.iter().map(|(_, ix)| ix.clone()).collect());
+
+            match close_executor.push(wallet_pubkey, instructions, signer.clone()) {
+                Ok(id) => {
+                    logger.log(format!("Queued close of {} token accounts {:?} for wallet {}", token_accounts.len(), token_accounts, i + 1));
+                    close_queued.push((id, wallet_pubkey, token_accounts));
                 }
-            },
+                Err(e) => {
+                    logger.log(format!("❌ Failed to queue close batch for wallet {}: {}", i + 1, e).red().to_string());
+                }
+            }
+        }
+    }
+
+    let mut close_outcomes = close_executor.join().await;
+    for (id, wallet_pubkey, token_accounts) in close_queued {
+        match close_outcomes.remove(&id) {
+            Some(TxOutcome::Confirmed(signature)) => {
+                logger.log(format!("✅ Closed {} token accounts {:?} for wallet {}, signature: {}",
+                                  token_accounts.len(), token_accounts, wallet_pubkey, signature));
+            }
+            Some(TxOutcome::Dropped(e)) => {
+                logger.log(format!("❌ {}", e).red().to_string());
+            }
+            None => {
+                logger.log(format!("❌ No confirmation outcome recorded closing token accounts for wallet {}", wallet_pubkey).red().to_string());
+            }
+        }
+    }
+
+    // Phase 2: collect remaining SOL from every wallet, again queued
+    // concurrently instead of one transfer-and-sleep at a time.
+    logger.log("Collecting remaining SOL from each wallet...".to_string());
+
+    let collect_executor = TransactionExecutor::new(config.app_state.rpc_client.clone());
+    let mut collect_queued = Vec::new();
+
+    for (i, wallet) in wallets.iter().enumerate() {
+        let wallet_pubkey = wallet.pubkey();
+
+        let balance = match config.app_state.rpc_client.get_balance(&wallet_pubkey) {
+            Ok(balance) => balance,
             Err(e) => {
                 logger.log(format!("❌ Failed to get balance for wallet {}: {}", i + 1, e).red().to_string());
+                continue;
+            }
+        };
+
+        if balance <= 5000 { // Leave minimum for rent
+            logger.log(format!("Wallet {} has insufficient balance to collect", i + 1));
+            continue;
+        }
+
+        let balance_to_send = balance - 5000; // Leave 5000 lamports for rent
+        let transfer_instruction = system_instruction::transfer(&wallet_pubkey, &main_wallet_pubkey, balance_to_send);
+        let instructions = with_priority_fee(config, vec![transfer_instruction]);
+        let signer: Arc<dyn Signer + Send + Sync> = Arc::new(wallet.clone());
+
+        match collect_executor.push(wallet_pubkey, instructions, signer) {
+            Ok(id) => {
+                logger.log(format!("Queued collection of {} SOL from wallet {}", balance_to_send as f64 / 1_000_000_000.0, i + 1));
+                collect_queued.push((id, wallet_pubkey, balance_to_send));
+            }
+            Err(e) => {
+                logger.log(format!("❌ Failed to queue collection from wallet {}: {}", i + 1, e).red().to_string());
             }
         }
-        
-        // Wait a bit to avoid rate limiting
-        tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
     }
-    
+
+    let mut collect_outcomes = collect_executor.join().await;
+    let mut total_collected = 0.0;
+    let mut results = Vec::new();
+
+    for (id, wallet_pubkey, balance_to_send) in collect_queued {
+        let balance_sol = balance_to_send as f64 / 1_000_000_000.0;
+        let (success, signature, error) = match collect_outcomes.remove(&id) {
+            Some(TxOutcome::Confirmed(signature)) => {
+                logger.log(format!("✅ Collected {} SOL from wallet {}, signature: {}", balance_sol, wallet_pubkey, signature));
+                total_collected += balance_sol;
+                (true, Some(signature.to_string()), None)
+            }
+            Some(TxOutcome::Dropped(e)) => {
+                logger.log(format!("❌ {}", e).red().to_string());
+                (false, None, Some(e))
+            }
+            None => {
+                let e = format!("No confirmation outcome recorded for wallet {}", wallet_pubkey);
+                logger.log(e.clone().red().to_string());
+                (false, None, Some(e))
+            }
+        };
+        results.push(WalletOpResult { wallet: wallet_pubkey.to_string(), lamports: balance_to_send, signature, success, error });
+    }
+
     logger.log(format!("Collection completed! Total collected: {} SOL", total_collected).green().bold().to_string());
+
+    output.emit(&CommandSummary {
+        command: "collect".to_string(),
+        succeeded: results.iter().filter(|r| r.success).count(),
+        failed: results.iter().filter(|r| !r.success).count(),
+        results,
+    });
+
     Ok(())
 }
 
+/// Interactive REPL for driving wallet-management commands without
+/// re-paying process startup (RPC client construction, blockhash processor
+/// warm-up) for every single operation. Reads one line at a time from
+/// stdin; `close`/`quit` ends the session and returns control to `main`,
+/// which shuts down the same way it would after any other command.
+async fn run_interactive_repl(config: &Config, output: OutputFormat) {
+    println!("Interactive mode. Commands: wrap <amt>, unwrap, distribute, collect, status, start-mm, stop-mm, close|quit");
+
+    let mut market_maker_handle: Option<tokio::task::JoinHandle<()>> = None;
+
+    loop {
+        print!("> ");
+        if std::io::Write::flush(&mut std::io::stdout()).is_err() {
+            break;
+        }
+
+        let mut line = String::new();
+        if std::io::stdin().read_line(&mut line).unwrap_or(0) == 0 {
+            // EOF (e.g. piped input ran out)
+            break;
+        }
+
+        let mut parts = line.trim().split_whitespace();
+        let command = match parts.next() {
+            Some(command) => command,
+            None => continue,
+        };
+
+        match command {
+            "close" | "quit" => {
+                if let Some(handle) = market_maker_handle.take() {
+                    handle.abort();
+                }
+                break;
+            }
+            "wrap" => {
+                let amount = parts.next()
+                    .and_then(|v| v.parse::<f64>().ok())
+                    .unwrap_or(0.1);
+                if let Err(e) = wrap_sol(config, amount).await {
+                    eprintln!("❌ Failed to wrap SOL: {}", e);
+                }
+            }
+            "unwrap" => {
+                if let Err(e) = unwrap_sol(config).await {
+                    eprintln!("❌ Failed to unwrap WSOL: {}", e);
+                }
+            }
+            "distribute" => {
+                if let Err(e) = distribute_sol(config, output).await {
+                    eprintln!("❌ Failed to distribute SOL: {}", e);
+                }
+            }
+            "collect" => {
+                if let Err(e) = collect_sol(config, output).await {
+                    eprintln!("❌ Failed to collect SOL: {}", e);
+                }
+            }
+            "status" => {
+                match config.app_state.rpc_client.get_balance(&config.app_state.wallet.pubkey()) {
+                    Ok(balance) => println!("Main wallet {}: {} SOL", config.app_state.wallet.pubkey(), balance as f64 / 1_000_000_000.0),
+                    Err(e) => eprintln!("❌ Failed to get main wallet balance: {}", e),
+                }
+                println!("Market maker running: {}", market_maker_handle.is_some());
+            }
+            "start-mm" => {
+                if market_maker_handle.is_some() {
+                    println!("Market maker is already running");
+                    continue;
+                }
+
+                let market_maker_config = MarketMakerConfig::stealth_mode(
+                    config.yellowstone_grpc_http.clone(),
+                    config.yellowstone_grpc_token.clone(),
+                    Arc::new(config.app_state.clone()),
+                    config.target_token_mint.clone(),
+                );
+
+                market_maker_handle = Some(tokio::spawn(async move {
+                    if let Err(e) = start_market_maker(market_maker_config).await {
+                        eprintln!("Advanced Market Maker error: {}", e);
+                    }
+                }));
+                println!("Market maker started");
+            }
+            "stop-mm" => {
+                match market_maker_handle.take() {
+                    Some(handle) => {
+                        handle.abort();
+                        println!("Market maker stopped");
+                    }
+                    None => println!("Market maker is not running"),
+                }
+            }
+            other => {
+                println!("Unknown command: {} (commands: wrap <amt>, unwrap, distribute, collect, status, start-mm, stop-mm, close|quit)", other);
+            }
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() {
     /* Initial Settings */
@@ -737,6 +1681,7 @@ async fn main() {
 
     // Parse command line arguments
     let args: Vec<String> = std::env::args().collect();
+    let output_format = OutputFormat::from_args(&args);
     if args.len() > 1 {
         // Check for wallet generation argument
         if args.contains(&"--wallet".to_string()) {
@@ -754,7 +1699,73 @@ async fn main() {
             }
         }
         // Check for command line arguments
-        else if args.contains(&"--wrap".to_string()) {
+        else if args.contains(&"--interactive".to_string()) {
+            run_interactive_repl(&config, output_format).await;
+            return;
+        }
+        else if args.contains(&"--build-only".to_string()) {
+            let destination = args.iter()
+                .position(|a| a == "--destination")
+                .and_then(|idx| args.get(idx + 1))
+                .ok_or_else(|| "Missing --destination <pubkey>".to_string())
+                .and_then(|v| Pubkey::from_str(v).map_err(|e| format!("Invalid --destination: {}", e)));
+
+            let lamports = args.iter()
+                .position(|a| a == "--lamports")
+                .and_then(|idx| args.get(idx + 1))
+                .ok_or_else(|| "Missing --lamports <amount>".to_string())
+                .and_then(|v| v.parse::<u64>().map_err(|e| format!("Invalid --lamports: {}", e)));
+
+            let blockhash_arg = args.iter()
+                .position(|a| a == "--blockhash")
+                .and_then(|idx| args.get(idx + 1))
+                .cloned();
+
+            let result = match (destination, lamports) {
+                (Ok(destination), Ok(lamports)) => build_offline_transfer(&config, destination, lamports, blockhash_arg).await,
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            };
+
+            match result {
+                Ok(_) => return,
+                Err(e) => {
+                    eprintln!("❌ Failed to build offline transaction: {}", e);
+                    return;
+                }
+            }
+        }
+        else if args.contains(&"--sign-offline".to_string()) {
+            match sign_offline_transaction(&config) {
+                Ok(_) => return,
+                Err(e) => {
+                    eprintln!("❌ Failed to sign offline transaction: {}", e);
+                    return;
+                }
+            }
+        }
+        else if args.contains(&"--broadcast".to_string()) {
+            match broadcast_offline_transaction(&config) {
+                Ok(_) => return,
+                Err(e) => {
+                    eprintln!("❌ Failed to broadcast offline transaction: {}", e);
+                    return;
+                }
+            }
+        }
+        else if args.contains(&"--airdrop".to_string()) {
+            println!("Requesting devnet/testnet airdrop...");
+
+            match airdrop_sol(&config).await {
+                Ok(_) => {
+                    println!("✅ Airdrop completed successfully!");
+                    return;
+                },
+                Err(e) => {
+                    eprintln!("❌ Failed to airdrop SOL: {}", e);
+                    return;
+                }
+            }
+        } else if args.contains(&"--wrap".to_string()) {
             println!("Wrapping SOL to WSOL...");
             
             // Get wrap amount from .env
@@ -789,7 +1800,7 @@ async fn main() {
         } else if args.contains(&"--close".to_string()) {
             println!("Closing all token accounts...");
             
-            match close_all_token_accounts(&config).await {
+            match close_all_token_accounts(&config, output_format).await {
                 Ok(_) => {
                     println!("Successfully closed all token accounts");
                     return;
@@ -805,7 +1816,7 @@ async fn main() {
         } else if args.contains(&"--distribute".to_string()) {
             println!("Distributing SOL to all wallets and converting to WSOL...");
             
-            match distribute_sol(&config).await {
+            match distribute_sol(&config, output_format).await {
                 Ok(_) => {
                     println!("✅ SOL distribution and WSOL conversion completed successfully!");
                     return;
@@ -818,7 +1829,7 @@ async fn main() {
         } else if args.contains(&"--collect".to_string()) {
             println!("Collecting all SOL from wallets back to main wallet...");
             
-            match collect_sol(&config).await {
+            match collect_sol(&config, output_format).await {
                 Ok(_) => {
                     println!("✅ SOL collection completed successfully!");
                     return;